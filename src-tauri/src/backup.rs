@@ -0,0 +1,56 @@
+//! Online snapshot/restore of `summit.db` so users can back up or transfer
+//! their data without closing the app.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use rusqlite::backup::Backup;
+use rusqlite::Connection;
+use sqlx::SqlitePool;
+
+/// Flushes the WAL into the main database file so a subsequent file-level
+/// copy sees a consistent, complete snapshot.
+async fn checkpoint(pool: &SqlitePool) -> Result<(), String> {
+    sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+        .execute(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Copies `source` into `dest` using SQLite's online backup API, which
+/// copies page-by-page under a read lock rather than requiring exclusive
+/// file access.
+async fn copy_via_backup_api(source: PathBuf, dest: PathBuf) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || -> Result<(), String> {
+        let src_conn = Connection::open(&source).map_err(|e| e.to_string())?;
+        let mut dst_conn = Connection::open(&dest).map_err(|e| e.to_string())?;
+        let backup = Backup::new(&src_conn, &mut dst_conn).map_err(|e| e.to_string())?;
+        backup
+            .run_to_completion(5, Duration::from_millis(250), None)
+            .map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Snapshots the live database at `db_path` to `dest_path`.
+pub async fn backup_database(
+    pool: &SqlitePool,
+    db_path: &Path,
+    dest_path: PathBuf,
+) -> Result<(), String> {
+    checkpoint(pool).await?;
+    copy_via_backup_api(db_path.to_path_buf(), dest_path).await
+}
+
+/// Restores the live database at `db_path` in place from a snapshot at
+/// `source_path`.
+pub async fn restore_database(
+    pool: &SqlitePool,
+    db_path: &Path,
+    source_path: PathBuf,
+) -> Result<(), String> {
+    checkpoint(pool).await?;
+    copy_via_backup_api(source_path, db_path.to_path_buf()).await
+}
@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+
+use crate::db::AppDb;
+
+/// Snapshots `summit.db` to `path` while the app keeps running.
+#[tauri::command]
+#[specta::specta]
+pub async fn backup_database(db: tauri::State<'_, AppDb>, path: PathBuf) -> Result<(), String> {
+    crate::backup::backup_database(&db.pool, &db.path, path).await
+}
+
+/// Restores `summit.db` in place from a snapshot previously written by
+/// [`backup_database`].
+#[tauri::command]
+#[specta::specta]
+pub async fn restore_database(db: tauri::State<'_, AppDb>, path: PathBuf) -> Result<(), String> {
+    crate::backup::restore_database(&db.pool, &db.path, path).await
+}
@@ -0,0 +1,83 @@
+use crate::db::AppDb;
+use crate::models::{HealthMetric, NewHealthMetric, VitalScore};
+
+/// Inserts a day of health metrics, or updates it in place if the `date`
+/// already exists.
+#[tauri::command]
+#[specta::specta]
+pub async fn insert_health_metric(
+    db: tauri::State<'_, AppDb>,
+    metric: NewHealthMetric,
+) -> Result<HealthMetric, String> {
+    sqlx::query_as::<_, HealthMetric>(
+        r#"
+        INSERT INTO health_metrics (
+            date, body_battery, sleep_score, sleep_duration_seconds,
+            deep_sleep_seconds, rem_sleep_seconds, stress_avg, resting_hr,
+            hrv_avg, intensity_minutes, steps
+        )
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT(date) DO UPDATE SET
+            body_battery = excluded.body_battery,
+            sleep_score = excluded.sleep_score,
+            sleep_duration_seconds = excluded.sleep_duration_seconds,
+            deep_sleep_seconds = excluded.deep_sleep_seconds,
+            rem_sleep_seconds = excluded.rem_sleep_seconds,
+            stress_avg = excluded.stress_avg,
+            resting_hr = excluded.resting_hr,
+            hrv_avg = excluded.hrv_avg,
+            intensity_minutes = excluded.intensity_minutes,
+            steps = excluded.steps,
+            updated_at = CURRENT_TIMESTAMP
+        RETURNING *
+        "#,
+    )
+    .bind(metric.date)
+    .bind(metric.body_battery)
+    .bind(metric.sleep_score)
+    .bind(metric.sleep_duration_seconds)
+    .bind(metric.deep_sleep_seconds)
+    .bind(metric.rem_sleep_seconds)
+    .bind(metric.stress_avg)
+    .bind(metric.resting_hr)
+    .bind(metric.hrv_avg)
+    .bind(metric.intensity_minutes)
+    .bind(metric.steps)
+    .fetch_one(&db.pool)
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// Fetches `health_metrics` rows between two ISO dates, inclusive, ordered
+/// oldest first.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_metrics_range(
+    db: tauri::State<'_, AppDb>,
+    start_date: String,
+    end_date: String,
+) -> Result<Vec<HealthMetric>, String> {
+    sqlx::query_as::<_, HealthMetric>(
+        "SELECT * FROM health_metrics WHERE date >= ? AND date <= ? ORDER BY date ASC",
+    )
+    .bind(start_date)
+    .bind(end_date)
+    .fetch_all(&db.pool)
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// Fetches the computed vital score for a single date, if one has been
+/// recorded.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_vital_score(
+    db: tauri::State<'_, AppDb>,
+    date: String,
+) -> Result<Option<VitalScore>, String> {
+    sqlx::query_as::<_, VitalScore>("SELECT * FROM vital_scores WHERE date = ?")
+        .bind(date)
+        .fetch_optional(&db.pool)
+        .await
+        .map_err(|e| e.to_string())
+}
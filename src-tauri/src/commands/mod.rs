@@ -0,0 +1,13 @@
+mod backup;
+mod metrics;
+mod rollback;
+mod scoring;
+#[cfg(feature = "mock")]
+mod seed;
+
+pub use backup::{backup_database, restore_database};
+pub use metrics::{get_metrics_range, get_vital_score, insert_health_metric};
+pub use rollback::rollback_to;
+pub use scoring::{recompute_trends, recompute_vital_score};
+#[cfg(feature = "mock")]
+pub use seed::load_demo_data;
@@ -0,0 +1,42 @@
+use crate::db::AppDb;
+use crate::migrations::MIGRATIONS;
+
+/// Undoes applied migrations in reverse order down to (but not including)
+/// `version`, running each registered `Down` script and removing its row
+/// from the plugin's `_sqlx_migrations` bookkeeping table.
+///
+/// This is the recovery path for a user stranded on a half-migrated
+/// `summit.db` after a bad schema change shipped upstream.
+#[tauri::command]
+#[specta::specta]
+pub async fn rollback_to(db: tauri::State<'_, AppDb>, version: i64) -> Result<(), String> {
+    let pool = &db.pool;
+
+    let applied: Vec<i64> = sqlx::query_scalar(
+        "SELECT version FROM _sqlx_migrations WHERE version > ? ORDER BY version DESC",
+    )
+    .bind(version)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    for v in applied {
+        let migration = MIGRATIONS
+            .iter()
+            .find(|m| m.version == v)
+            .ok_or_else(|| format!("no Down migration registered for version {v}"))?;
+
+        sqlx::query(migration.down)
+            .execute(pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        sqlx::query("DELETE FROM _sqlx_migrations WHERE version = ?")
+            .bind(v)
+            .execute(pool)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
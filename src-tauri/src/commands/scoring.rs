@@ -0,0 +1,45 @@
+use crate::db::AppDb;
+use crate::models::{Trend, VitalScore};
+use crate::scoring;
+
+/// Recomputes and upserts the vital score for `date` from `health_metrics`.
+/// Returns `None` if there is no data for that date.
+#[tauri::command]
+#[specta::specta]
+pub async fn recompute_vital_score(
+    db: tauri::State<'_, AppDb>,
+    date: String,
+) -> Result<Option<VitalScore>, String> {
+    if scoring::compute_vital_score(&db.pool, &date)
+        .await
+        .map_err(|e| e.to_string())?
+        .is_none()
+    {
+        return Ok(None);
+    }
+
+    sqlx::query_as::<_, VitalScore>("SELECT * FROM vital_scores WHERE date = ?")
+        .bind(date)
+        .fetch_optional(&db.pool)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Recomputes and upserts `trends` rows for every tracked metric under
+/// `timeframe`.
+#[tauri::command]
+#[specta::specta]
+pub async fn recompute_trends(
+    db: tauri::State<'_, AppDb>,
+    timeframe: String,
+) -> Result<Vec<Trend>, String> {
+    scoring::compute_trends(&db.pool, &timeframe)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    sqlx::query_as::<_, Trend>("SELECT * FROM trends WHERE timeframe = ?")
+        .bind(timeframe)
+        .fetch_all(&db.pool)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,11 @@
+use crate::db::AppDb;
+
+/// Populates `health_metrics` with realistic synthetic data for tests and
+/// first-run demo mode. Only registered when the `mock` feature is enabled.
+#[tauri::command]
+#[specta::specta]
+pub async fn load_demo_data(db: tauri::State<'_, AppDb>, days: u32) -> Result<(), String> {
+    crate::seed::load_demo_data(&db.pool, days)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,63 @@
+use std::path::{Path, PathBuf};
+
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous};
+use sqlx::SqlitePool;
+use tauri::Manager;
+
+use crate::migrations;
+
+/// Filename `tauri_plugin_sql` is registered against (`"sqlite:summit.db"`);
+/// kept as one constant so both pools resolve the same file.
+pub const DB_FILENAME: &str = "summit.db";
+
+/// Shared sqlx pool for Rust-side commands (scoring, backup, rollback) that
+/// need direct SQL access beyond what the `tauri-plugin-sql` JS bridge
+/// exposes to the frontend. Points at the same `summit.db` file.
+pub struct AppDb {
+    pub pool: SqlitePool,
+    pub path: PathBuf,
+}
+
+/// Resolves `summit.db` to the same app-data directory `tauri_plugin_sql`
+/// resolves its `"sqlite:summit.db"` URL against, so this pool and the
+/// plugin's always point at the same physical file rather than whatever
+/// the process's current working directory happens to be.
+pub fn resolve_db_path(app: &tauri::AppHandle) -> Result<PathBuf, sqlx::Error> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| sqlx::Error::Configuration(e.to_string().into()))?;
+    Ok(dir.join(DB_FILENAME))
+}
+
+/// Connects a Rust-side sqlx pool to `path` and applies `migrations::MIGRATIONS`
+/// against it directly, rather than assuming `tauri_plugin_sql`'s own pass
+/// against the same file already ran first. Every migration's `up` SQL is
+/// idempotent (`IF NOT EXISTS` / re-runnable `PRAGMA`s), so running it here
+/// too is safe regardless of which pool reaches the file first.
+pub async fn connect(path: &Path) -> Result<AppDb, sqlx::Error> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(sqlx::Error::Io)?;
+    }
+
+    let options = SqliteConnectOptions::new()
+        .filename(path)
+        .create_if_missing(true)
+        .journal_mode(SqliteJournalMode::Wal)
+        .synchronous(SqliteSynchronous::Normal)
+        .foreign_keys(true);
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(5)
+        .connect_with(options)
+        .await?;
+
+    for migration in migrations::MIGRATIONS {
+        sqlx::query(migration.up).execute(&pool).await?;
+    }
+
+    Ok(AppDb {
+        pool,
+        path: path.to_path_buf(),
+    })
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use sqlx::FromRow;
+
+/// Mirrors a row of `health_metrics`.
+#[derive(Debug, Clone, Serialize, Deserialize, Type, FromRow)]
+pub struct HealthMetric {
+    pub id: i64,
+    pub date: String,
+    pub body_battery: Option<i64>,
+    pub sleep_score: Option<i64>,
+    pub sleep_duration_seconds: Option<i64>,
+    pub deep_sleep_seconds: Option<i64>,
+    pub rem_sleep_seconds: Option<i64>,
+    pub stress_avg: Option<i64>,
+    pub resting_hr: Option<i64>,
+    pub hrv_avg: Option<i64>,
+    pub intensity_minutes: Option<i64>,
+    pub steps: Option<i64>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Payload for inserting or upserting a day of `health_metrics`.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct NewHealthMetric {
+    pub date: String,
+    pub body_battery: Option<i64>,
+    pub sleep_score: Option<i64>,
+    pub sleep_duration_seconds: Option<i64>,
+    pub deep_sleep_seconds: Option<i64>,
+    pub rem_sleep_seconds: Option<i64>,
+    pub stress_avg: Option<i64>,
+    pub resting_hr: Option<i64>,
+    pub hrv_avg: Option<i64>,
+    pub intensity_minutes: Option<i64>,
+    pub steps: Option<i64>,
+}
+
+/// Mirrors a row of `vital_scores`.
+#[derive(Debug, Clone, Serialize, Deserialize, Type, FromRow)]
+pub struct VitalScore {
+    pub id: i64,
+    pub date: String,
+    pub score: i64,
+    pub sleep_component: Option<i64>,
+    pub recovery_component: Option<i64>,
+    pub strain_component: Option<i64>,
+    pub recommendation: Option<String>,
+    pub created_at: String,
+}
+
+/// Mirrors a row of `trends`.
+#[derive(Debug, Clone, Serialize, Deserialize, Type, FromRow)]
+pub struct Trend {
+    pub id: i64,
+    pub metric: String,
+    pub timeframe: String,
+    pub baseline: Option<f64>,
+    pub current_avg: Option<f64>,
+    pub percent_change: Option<f64>,
+    pub direction: Option<String>,
+    pub detected_at: String,
+}
@@ -0,0 +1,20 @@
+//! Computes `vital_scores` and `trends` rows from raw `health_metrics`.
+//!
+//! This module holds the pure computation + persistence logic; the
+//! `recompute_vital_score`/`recompute_trends` Tauri commands in
+//! [`crate::commands::scoring`] are thin wrappers around it.
+
+mod trends;
+mod vital_score;
+
+pub use trends::{compute_trends, TrendResult};
+pub use vital_score::{compute_vital_score, VitalScoreResult};
+
+/// Minimum number of non-NULL days required in a window before it is
+/// trusted for averaging; windows with fewer valid days are skipped rather
+/// than computed from a handful of noisy samples.
+const MIN_VALID_DAYS: i64 = 3;
+
+fn clamp_0_100(value: f64) -> f64 {
+    value.clamp(0.0, 100.0)
+}
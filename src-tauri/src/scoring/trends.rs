@@ -0,0 +1,243 @@
+use sqlx::SqlitePool;
+
+use super::MIN_VALID_DAYS;
+
+/// Metrics tracked for trend detection, paired with their `health_metrics`
+/// column name.
+const TRACKED_METRICS: &[(&str, &str)] = &[
+    ("body_battery", "body_battery"),
+    ("sleep_score", "sleep_score"),
+    ("hrv_avg", "hrv_avg"),
+    ("resting_hr", "resting_hr"),
+    ("steps", "steps"),
+    ("intensity_minutes", "intensity_minutes"),
+];
+
+const STABLE_BAND_PERCENT: f64 = 5.0;
+
+/// `baseline` covers the trailing 30 days, excluding the most recent 7 (i.e.
+/// days 7 through 36 ago); `current_avg` covers the most recent 7 (days 0
+/// through 6 ago).
+const BASELINE_START_OFFSET_DAYS: i64 = -36;
+const BASELINE_END_OFFSET_DAYS: i64 = -7;
+const CURRENT_START_OFFSET_DAYS: i64 = -6;
+const CURRENT_END_OFFSET_DAYS: i64 = 0;
+
+pub struct TrendResult {
+    pub metric: String,
+    pub baseline: f64,
+    pub current_avg: f64,
+    pub percent_change: f64,
+    pub direction: &'static str,
+}
+
+#[derive(sqlx::FromRow)]
+struct WindowAverage {
+    avg_value: Option<f64>,
+    valid_days: i64,
+}
+
+/// Recomputes `trends` rows for every tracked metric under `timeframe`,
+/// anchored on the most recent date present in `health_metrics`. Metrics
+/// without at least [`MIN_VALID_DAYS`] valid days in either window are
+/// skipped.
+pub async fn compute_trends(
+    pool: &SqlitePool,
+    timeframe: &str,
+) -> Result<Vec<TrendResult>, sqlx::Error> {
+    let Some(latest) = sqlx::query_scalar::<_, Option<String>>("SELECT MAX(date) FROM health_metrics")
+        .fetch_one(pool)
+        .await?
+    else {
+        return Ok(vec![]);
+    };
+
+    let mut results = Vec::new();
+    for (metric, column) in TRACKED_METRICS {
+        if let Some(result) = compute_metric_trend(pool, metric, column, &latest).await? {
+            upsert_trend(pool, &result, timeframe).await?;
+            results.push(result);
+        }
+    }
+
+    Ok(results)
+}
+
+async fn compute_metric_trend(
+    pool: &SqlitePool,
+    metric: &str,
+    column: &str,
+    latest: &str,
+) -> Result<Option<TrendResult>, sqlx::Error> {
+    let baseline = window_average(
+        pool,
+        column,
+        latest,
+        BASELINE_START_OFFSET_DAYS,
+        BASELINE_END_OFFSET_DAYS,
+    )
+    .await?;
+    let current = window_average(
+        pool,
+        column,
+        latest,
+        CURRENT_START_OFFSET_DAYS,
+        CURRENT_END_OFFSET_DAYS,
+    )
+    .await?;
+
+    if baseline.valid_days < MIN_VALID_DAYS || current.valid_days < MIN_VALID_DAYS {
+        return Ok(None);
+    }
+    let (Some(baseline_avg), Some(current_avg)) = (baseline.avg_value, current.avg_value) else {
+        return Ok(None);
+    };
+    if baseline_avg == 0.0 {
+        return Ok(None);
+    }
+
+    let percent_change = (current_avg - baseline_avg) / baseline_avg * 100.0;
+    let direction = direction_for(percent_change);
+
+    Ok(Some(TrendResult {
+        metric: metric.to_string(),
+        baseline: baseline_avg,
+        current_avg,
+        percent_change,
+        direction,
+    }))
+}
+
+fn direction_for(percent_change: f64) -> &'static str {
+    if percent_change.abs() <= STABLE_BAND_PERCENT {
+        "stable"
+    } else if percent_change > 0.0 {
+        "up"
+    } else {
+        "down"
+    }
+}
+
+async fn window_average(
+    pool: &SqlitePool,
+    column: &str,
+    latest: &str,
+    start_offset_days: i64,
+    end_offset_days: i64,
+) -> Result<WindowAverage, sqlx::Error> {
+    let query = format!(
+        "SELECT AVG({column}) as avg_value, COUNT({column}) as valid_days
+         FROM health_metrics
+         WHERE date BETWEEN date(?, ?) AND date(?, ?)"
+    );
+
+    sqlx::query_as::<_, WindowAverage>(&query)
+        .bind(latest)
+        .bind(format!("{start_offset_days} days"))
+        .bind(latest)
+        .bind(format!("{end_offset_days} days"))
+        .fetch_one(pool)
+        .await
+}
+
+async fn upsert_trend(pool: &SqlitePool, result: &TrendResult, timeframe: &str) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO trends (metric, timeframe, baseline, current_avg, percent_change, direction)
+        VALUES (?, ?, ?, ?, ?, ?)
+        ON CONFLICT(metric, timeframe) DO UPDATE SET
+            baseline = excluded.baseline,
+            current_avg = excluded.current_avg,
+            percent_change = excluded.percent_change,
+            direction = excluded.direction,
+            detected_at = CURRENT_TIMESTAMP
+        "#,
+    )
+    .bind(&result.metric)
+    .bind(timeframe)
+    .bind(result.baseline)
+    .bind(result.current_avg)
+    .bind(result.percent_change)
+    .bind(result.direction)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::sqlite::SqlitePoolOptions;
+    use sqlx::SqlitePool;
+
+    use super::{compute_trends, direction_for};
+
+    #[test]
+    fn stays_stable_within_the_dead_band() {
+        assert_eq!(direction_for(0.0), "stable");
+        assert_eq!(direction_for(5.0), "stable");
+        assert_eq!(direction_for(-5.0), "stable");
+    }
+
+    #[test]
+    fn flips_up_or_down_outside_the_dead_band() {
+        assert_eq!(direction_for(5.01), "up");
+        assert_eq!(direction_for(-5.01), "down");
+    }
+
+    async fn memory_pool_with_schema() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("open in-memory db");
+
+        for migration in crate::migrations::MIGRATIONS {
+            sqlx::query(migration.up)
+                .execute(&pool)
+                .await
+                .expect("apply migration");
+        }
+
+        pool
+    }
+
+    /// Inserts a `steps` row at `offset_days` from a fixed anchor date,
+    /// letting SQLite's own `date()` do the arithmetic so the test doesn't
+    /// need a `chrono` dependency of its own.
+    async fn insert_steps_at_offset(pool: &SqlitePool, anchor: &str, offset_days: i64, steps: i64) {
+        sqlx::query("INSERT INTO health_metrics (date, steps) VALUES (date(?, ?), ?)")
+            .bind(anchor)
+            .bind(format!("{offset_days} days"))
+            .bind(steps)
+            .execute(pool)
+            .await
+            .expect("insert health_metrics row");
+    }
+
+    #[tokio::test]
+    async fn compute_trends_pins_the_exact_baseline_current_avg_and_percent_change() {
+        let pool = memory_pool_with_schema().await;
+        let anchor = "2026-07-26";
+
+        // Baseline window (days 7 through 36 ago, inclusive): steps flat at 8000.
+        for offset in 7..=36 {
+            insert_steps_at_offset(&pool, anchor, -offset, 8000).await;
+        }
+        // Current window (most recent 7 days, including today): steps at 9600, +20%.
+        for offset in 0..=6 {
+            insert_steps_at_offset(&pool, anchor, -offset, 9600).await;
+        }
+
+        let trends = compute_trends(&pool, "weekly").await.expect("compute trends");
+        let steps_trend = trends
+            .iter()
+            .find(|t| t.metric == "steps")
+            .expect("steps trend computed");
+
+        assert_eq!(steps_trend.baseline, 8000.0);
+        assert_eq!(steps_trend.current_avg, 9600.0);
+        assert!((steps_trend.percent_change - 20.0).abs() < 1e-9);
+        assert_eq!(steps_trend.direction, "up");
+    }
+}
@@ -0,0 +1,270 @@
+use sqlx::SqlitePool;
+
+use super::{clamp_0_100, MIN_VALID_DAYS};
+
+const SLEEP_TARGET_SECONDS: f64 = 8.0 * 3600.0;
+const INTENSITY_TARGET_MINUTES: f64 = 30.0;
+const STEPS_TARGET: f64 = 10_000.0;
+const RECOVERY_BASELINE_WINDOW_DAYS: i64 = 30;
+
+pub struct VitalScoreResult {
+    pub score: i64,
+    pub sleep_component: Option<i64>,
+    pub recovery_component: Option<i64>,
+    pub strain_component: Option<i64>,
+    pub recommendation: String,
+}
+
+#[derive(sqlx::FromRow)]
+struct DayMetrics {
+    sleep_score: Option<i64>,
+    sleep_duration_seconds: Option<i64>,
+    deep_sleep_seconds: Option<i64>,
+    rem_sleep_seconds: Option<i64>,
+    resting_hr: Option<i64>,
+    hrv_avg: Option<i64>,
+    intensity_minutes: Option<i64>,
+    steps: Option<i64>,
+}
+
+#[derive(sqlx::FromRow)]
+struct Baseline {
+    avg_hrv: Option<f64>,
+    valid_hrv_days: i64,
+    avg_resting_hr: Option<f64>,
+    valid_resting_hr_days: i64,
+}
+
+/// Recomputes the vital score for `date` from `health_metrics` and upserts
+/// it into `vital_scores`. Returns `None` (without writing anything) when
+/// there is no `health_metrics` row for that date.
+pub async fn compute_vital_score(
+    pool: &SqlitePool,
+    date: &str,
+) -> Result<Option<VitalScoreResult>, sqlx::Error> {
+    let Some(day) = sqlx::query_as::<_, DayMetrics>(
+        "SELECT sleep_score, sleep_duration_seconds, deep_sleep_seconds, rem_sleep_seconds,
+                resting_hr, hrv_avg, intensity_minutes, steps
+         FROM health_metrics WHERE date = ?",
+    )
+    .bind(date)
+    .fetch_optional(pool)
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    let baseline = sqlx::query_as::<_, Baseline>(
+        "SELECT AVG(hrv_avg) as avg_hrv, COUNT(hrv_avg) as valid_hrv_days,
+                AVG(resting_hr) as avg_resting_hr, COUNT(resting_hr) as valid_resting_hr_days
+         FROM health_metrics
+         WHERE date BETWEEN date(?, ?) AND ?",
+    )
+    .bind(date)
+    .bind(format!("-{} days", RECOVERY_BASELINE_WINDOW_DAYS - 1))
+    .bind(date)
+    .fetch_one(pool)
+    .await?;
+
+    let sleep_component = sleep_component(&day);
+    let recovery_component = recovery_component(&day, &baseline);
+    let strain_component = strain_component(&day);
+
+    let Some((score, recommendation)) =
+        total_score(sleep_component, recovery_component, strain_component)
+    else {
+        return Ok(None);
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO vital_scores (date, score, sleep_component, recovery_component, strain_component, recommendation)
+        VALUES (?, ?, ?, ?, ?, ?)
+        ON CONFLICT(date) DO UPDATE SET
+            score = excluded.score,
+            sleep_component = excluded.sleep_component,
+            recovery_component = excluded.recovery_component,
+            strain_component = excluded.strain_component,
+            recommendation = excluded.recommendation
+        "#,
+    )
+    .bind(date)
+    .bind(score)
+    .bind(sleep_component)
+    .bind(recovery_component)
+    .bind(strain_component)
+    .bind(&recommendation)
+    .execute(pool)
+    .await?;
+
+    Ok(Some(VitalScoreResult {
+        score,
+        sleep_component,
+        recovery_component,
+        strain_component,
+        recommendation,
+    }))
+}
+
+fn sleep_component(day: &DayMetrics) -> Option<i64> {
+    let duration_closeness = day.sleep_duration_seconds.map(|seconds| {
+        clamp_0_100(100.0 - (seconds as f64 - SLEEP_TARGET_SECONDS).abs() / SLEEP_TARGET_SECONDS * 100.0)
+    });
+
+    let deep_rem_fraction = match (day.sleep_duration_seconds, day.deep_sleep_seconds, day.rem_sleep_seconds) {
+        (Some(total), Some(deep), Some(rem)) if total > 0 => {
+            Some(clamp_0_100((deep + rem) as f64 / total as f64 * 100.0))
+        }
+        _ => None,
+    };
+
+    weighted_average(&[
+        (day.sleep_score.map(|v| v as f64), 0.5),
+        (duration_closeness, 0.25),
+        (deep_rem_fraction, 0.25),
+    ])
+}
+
+fn recovery_component(day: &DayMetrics, baseline: &Baseline) -> Option<i64> {
+    let hrv_deviation = match (day.hrv_avg, baseline.avg_hrv) {
+        (Some(value), Some(base)) if base > 0.0 && baseline.valid_hrv_days >= MIN_VALID_DAYS => {
+            Some((value as f64 - base) / base * 100.0)
+        }
+        _ => None,
+    };
+    let resting_hr_deviation = match (day.resting_hr, baseline.avg_resting_hr) {
+        (Some(value), Some(base))
+            if base > 0.0 && baseline.valid_resting_hr_days >= MIN_VALID_DAYS =>
+        {
+            Some((value as f64 - base) / base * 100.0)
+        }
+        _ => None,
+    };
+
+    weighted_average(&[
+        (hrv_deviation.map(|dev| clamp_0_100(50.0 + dev * 0.5)), 0.5),
+        (resting_hr_deviation.map(|dev| clamp_0_100(50.0 - dev * 0.5)), 0.5),
+    ])
+}
+
+fn strain_component(day: &DayMetrics) -> Option<i64> {
+    let intensity_achievement = day
+        .intensity_minutes
+        .map(|minutes| clamp_0_100(minutes as f64 / INTENSITY_TARGET_MINUTES * 100.0));
+    let steps_achievement = day.steps.map(|steps| clamp_0_100(steps as f64 / STEPS_TARGET * 100.0));
+
+    weighted_average(&[(intensity_achievement, 0.5), (steps_achievement, 0.5)])
+}
+
+/// Averages the available `(value, weight)` pairs, renormalizing weights
+/// over whichever inputs are present. Returns `None` if none are.
+fn weighted_average(parts: &[(Option<f64>, f64)]) -> Option<i64> {
+    let (weighted_sum, weight_total) = parts
+        .iter()
+        .filter_map(|(value, weight)| value.map(|v| (v * weight, *weight)))
+        .fold((0.0, 0.0), |(sum, total), (v, w)| (sum + v, total + w));
+
+    if weight_total == 0.0 {
+        None
+    } else {
+        Some((weighted_sum / weight_total).round() as i64)
+    }
+}
+
+fn total_score(
+    sleep: Option<i64>,
+    recovery: Option<i64>,
+    strain: Option<i64>,
+) -> Option<(i64, String)> {
+    let parts = [(sleep, 0.4), (recovery, 0.4), (strain, 0.2)];
+    let weighted_average = weighted_average(
+        &parts
+            .iter()
+            .map(|(value, weight)| (value.map(|v| v as f64), *weight))
+            .collect::<Vec<_>>(),
+    )?;
+
+    let lowest = [("sleep", sleep), ("recovery", recovery), ("strain", strain)]
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|v| (name, v)))
+        .min_by_key(|(_, v)| *v)
+        .map(|(name, _)| name)
+        .unwrap_or("sleep");
+
+    let recommendation = match lowest {
+        "sleep" => "Your sleep is the biggest drag on your vital score — aim for a full 8 hours with more deep and REM sleep.",
+        "recovery" => "Your recovery is lagging — elevated resting heart rate and/or low HRV suggest your body needs more rest.",
+        _ => "Your strain is low — more daily movement and intensity minutes would help your score.",
+    };
+
+    Some((weighted_average, recommendation.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weighted_average_renormalizes_over_present_values_only() {
+        assert_eq!(weighted_average(&[(Some(100.0), 0.5), (Some(0.0), 0.5)]), Some(50));
+        assert_eq!(weighted_average(&[(Some(80.0), 0.5), (None, 0.5)]), Some(80));
+        assert_eq!(weighted_average(&[(None, 0.5), (None, 0.5)]), None);
+    }
+
+    #[test]
+    fn total_score_is_none_when_every_component_is_missing() {
+        assert_eq!(total_score(None, None, None), None);
+    }
+
+    #[test]
+    fn total_score_recommends_the_lowest_component() {
+        let (score, recommendation) = total_score(Some(90), Some(40), Some(90)).unwrap();
+        assert_eq!(score, (0.4 * 90.0 + 0.4 * 40.0 + 0.2 * 90.0).round() as i64);
+        assert!(recommendation.contains("recovery"));
+
+        let (_, recommendation) = total_score(Some(30), Some(90), Some(90)).unwrap();
+        assert!(recommendation.contains("sleep"));
+
+        let (_, recommendation) = total_score(Some(90), Some(90), Some(10)).unwrap();
+        assert!(recommendation.contains("strain"));
+    }
+
+    fn day_with(hrv_avg: Option<i64>, resting_hr: Option<i64>) -> DayMetrics {
+        DayMetrics {
+            sleep_score: None,
+            sleep_duration_seconds: None,
+            deep_sleep_seconds: None,
+            rem_sleep_seconds: None,
+            resting_hr,
+            hrv_avg,
+            intensity_minutes: None,
+            steps: None,
+        }
+    }
+
+    #[test]
+    fn recovery_component_ignores_a_baseline_with_too_few_valid_days() {
+        let day = day_with(Some(55), Some(60));
+        let thin_baseline = Baseline {
+            avg_hrv: Some(55.0),
+            valid_hrv_days: 1,
+            avg_resting_hr: Some(60.0),
+            valid_resting_hr_days: 1,
+        };
+        assert_eq!(recovery_component(&day, &thin_baseline), None);
+    }
+
+    #[test]
+    fn recovery_component_uses_a_baseline_with_enough_valid_days() {
+        let day = day_with(Some(60), Some(55));
+        let solid_baseline = Baseline {
+            avg_hrv: Some(50.0),
+            valid_hrv_days: 10,
+            avg_resting_hr: Some(60.0),
+            valid_resting_hr_days: 10,
+        };
+        // Above-baseline HRV and below-baseline resting HR both push the
+        // recovery score above the neutral midpoint.
+        assert!(recovery_component(&day, &solid_baseline).unwrap() > 50);
+    }
+}
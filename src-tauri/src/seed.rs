@@ -0,0 +1,152 @@
+//! Synthetic `health_metrics` generation for tests and first-run demo mode.
+//!
+//! Only compiled in when the `mock` feature is enabled, so it never ships
+//! in a release build talking to a user's real `summit.db`.
+
+use chrono::{Duration, NaiveDate, Utc};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::SqlitePool;
+
+use crate::models::NewHealthMetric;
+
+/// Opens a self-contained in-memory database with the schema already
+/// applied. Pinned to a single pooled connection, since each connection to
+/// `sqlite::memory:` is otherwise its own independent, empty database.
+pub async fn open_mock_pool() -> Result<SqlitePool, sqlx::Error> {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await?;
+
+    for migration in crate::migrations::MIGRATIONS {
+        sqlx::query(migration.up).execute(&pool).await?;
+    }
+
+    Ok(pool)
+}
+
+/// Generates and inserts `days` of realistic synthetic `health_metrics`
+/// ending today.
+pub async fn load_demo_data(pool: &SqlitePool, days: u32) -> Result<(), sqlx::Error> {
+    for metric in generate_demo_metrics(days) {
+        sqlx::query(
+            r#"
+            INSERT INTO health_metrics (
+                date, body_battery, sleep_score, sleep_duration_seconds,
+                deep_sleep_seconds, rem_sleep_seconds, stress_avg, resting_hr,
+                hrv_avg, intensity_minutes, steps
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(date) DO NOTHING
+            "#,
+        )
+        .bind(metric.date)
+        .bind(metric.body_battery)
+        .bind(metric.sleep_score)
+        .bind(metric.sleep_duration_seconds)
+        .bind(metric.deep_sleep_seconds)
+        .bind(metric.rem_sleep_seconds)
+        .bind(metric.stress_avg)
+        .bind(metric.resting_hr)
+        .bind(metric.hrv_avg)
+        .bind(metric.intensity_minutes)
+        .bind(metric.steps)
+        .execute(pool)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Random-walks each metric day over day so consecutive days stay close to
+/// one another, the way a real person's vitals do, instead of being
+/// independently random.
+fn generate_demo_metrics(days: u32) -> Vec<NewHealthMetric> {
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let mut body_battery = 70.0;
+    let mut sleep_score = 75.0;
+    let mut sleep_duration = 7.5 * 3600.0;
+    let mut stress_avg = 35.0;
+    let mut resting_hr = 60.0;
+    let mut hrv = 55.0;
+    let mut intensity = 25.0;
+    let mut steps = 8000.0;
+
+    dates_ending_today(days)
+        .into_iter()
+        .map(|date| {
+            body_battery = walk(&mut rng, body_battery, 6.0, 20.0, 100.0);
+            sleep_score = walk(&mut rng, sleep_score, 5.0, 40.0, 100.0);
+            sleep_duration = walk(&mut rng, sleep_duration, 1800.0, 4.0 * 3600.0, 10.0 * 3600.0);
+            stress_avg = walk(&mut rng, stress_avg, 6.0, 5.0, 90.0);
+            resting_hr = walk(&mut rng, resting_hr, 2.0, 45.0, 85.0);
+            hrv = walk(&mut rng, hrv, 4.0, 25.0, 100.0);
+            intensity = walk(&mut rng, intensity, 8.0, 0.0, 90.0);
+            steps = walk(&mut rng, steps, 1500.0, 1000.0, 18000.0);
+
+            let deep_fraction: f64 = rng.gen_range(0.12..0.22);
+            let rem_fraction: f64 = rng.gen_range(0.15..0.25);
+
+            NewHealthMetric {
+                date: date.format("%Y-%m-%d").to_string(),
+                body_battery: Some(body_battery.round() as i64),
+                sleep_score: Some(sleep_score.round() as i64),
+                sleep_duration_seconds: Some(sleep_duration.round() as i64),
+                deep_sleep_seconds: Some((sleep_duration * deep_fraction).round() as i64),
+                rem_sleep_seconds: Some((sleep_duration * rem_fraction).round() as i64),
+                stress_avg: Some(stress_avg.round() as i64),
+                resting_hr: Some(resting_hr.round() as i64),
+                hrv_avg: Some(hrv.round() as i64),
+                intensity_minutes: Some(intensity.round() as i64),
+                steps: Some(steps.round() as i64),
+            }
+        })
+        .collect()
+}
+
+fn walk(rng: &mut StdRng, prev: f64, volatility: f64, min: f64, max: f64) -> f64 {
+    let delta = rng.gen_range(-volatility..=volatility);
+    (prev + delta).clamp(min, max)
+}
+
+fn dates_ending_today(days: u32) -> Vec<NaiveDate> {
+    let today = Utc::now().date_naive();
+    (0..days)
+        .rev()
+        .map(|offset| today - Duration::days(offset as i64))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring;
+
+    #[tokio::test]
+    async fn demo_data_feeds_the_scoring_pipeline() {
+        let pool = open_mock_pool().await.expect("open mock pool");
+        load_demo_data(&pool, 45).await.expect("seed demo data");
+
+        let latest: String = sqlx::query_scalar("SELECT MAX(date) FROM health_metrics")
+            .fetch_one(&pool)
+            .await
+            .expect("read latest seeded date");
+
+        let vital_score = scoring::compute_vital_score(&pool, &latest)
+            .await
+            .expect("compute vital score")
+            .expect("vital score for the seeded date");
+        assert!((0..=100).contains(&vital_score.score));
+
+        let trends = scoring::compute_trends(&pool, "weekly")
+            .await
+            .expect("compute trends");
+        assert!(!trends.is_empty());
+        for trend in &trends {
+            assert!(matches!(trend.direction, "up" | "down" | "stable"));
+        }
+    }
+}